@@ -1,93 +1,348 @@
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 
 use serde_yaml::{Mapping, Value};
+use thiserror::Error;
 
-use crate::{ConfigFile, GenerateConfig, GenerateMode};
+use crate::{ConfigFile, GenerateConfig, GenerateMode, ProjectConfig};
+
+/// Name of the implicit project used when a config file has no top-level
+/// `projects` mapping.
+const DEFAULT_PROJECT_NAME: &str = "default";
+
+/// Error that occurs while parsing a config file.
+///
+/// Errors carry the dotted key path of the offending field (e.g.
+/// `extensions.nitrogql.generate.mode`). They do not carry a YAML
+/// line/column: `serde_yaml::Value` discards span information once parsed,
+/// so pinpointing a location would require parsing with a span-tracking
+/// YAML library instead. The key path is the best diagnostic available
+/// without that change.
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to parse config file as YAML: {0}")]
+    InvalidYaml(#[from] serde_yaml::Error),
+    #[error("'{key}' must be a string or a list of strings")]
+    InvalidStringOrList { key: String },
+    #[error("'{key}' must be a string")]
+    InvalidString { key: String },
+    #[error("'{key}' must be a boolean")]
+    InvalidBool { key: String },
+    #[error("'{key}' must be a mapping")]
+    InvalidMapping { key: String },
+    #[error("unknown generate mode '{found}' for '{key}'")]
+    UnknownGenerateMode { key: String, found: String },
+}
 
 /// Parse config file from given string.
-/// Returns None if there is a validation error.
-pub fn parse_config(source: &str) -> Option<ConfigFile> {
-    let parsed: Value = serde_yaml::from_str(&source).ok()?;
+/// Returns `Err` with details if the config file is malformed.
+pub fn parse_config(source: &str) -> Result<ConfigFile, ConfigError> {
+    let parsed: Value = serde_yaml::from_str(source)?;
 
     read_config(parsed)
 }
 
-fn read_config(config: Value) -> Option<ConfigFile> {
-    let schema = 'schema: {
-        let schema = config.get("schema");
-        let Some(schema) = schema else {
-            break 'schema None;
-        };
-        if let Some(string) = schema.as_str() {
-            break 'schema Some(vec![string.to_owned()]);
-        }
-        if let Some(seq) = schema.as_sequence() {
-            let strs: Option<Vec<String>> = seq
-                .iter()
-                .map(|value| value.as_str().map(|s| s.to_owned()))
-                .collect();
-            let strs = strs?;
-            break 'schema Some(strs);
-        }
-        None
-    };
-    let documents = 'documents: {
-        let documents = config.get("documents");
-        let Some(documents) = documents else {
-            break 'documents None;
-        };
-        if let Some(string) = documents.as_str() {
-            break 'documents Some(vec![string.to_owned()]);
+fn read_config(config: Value) -> Result<ConfigFile, ConfigError> {
+    if let Some(projects) = config.get("projects") {
+        let projects = projects.as_mapping().ok_or_else(|| ConfigError::InvalidMapping {
+            key: "projects".to_owned(),
+        })?;
+        let mut parsed_projects = HashMap::new();
+        for (name, project) in projects.iter() {
+            let name = name.as_str().ok_or_else(|| ConfigError::InvalidString {
+                key: "projects".to_owned(),
+            })?;
+            let path = key_path("projects", name);
+            parsed_projects.insert(name.to_owned(), read_project_config(project, &path)?);
         }
-        if let Some(seq) = documents.as_sequence() {
-            let strs: Option<Vec<String>> = seq
-                .iter()
-                .map(|value| value.as_str().map(|s| s.to_owned()))
-                .collect();
-            let strs = strs?;
-            break 'documents Some(strs);
+        return Ok(ConfigFile {
+            schema: None,
+            documents: None,
+            generate: GenerateConfig::default(),
+            projects: Some(parsed_projects),
+        });
+    }
+
+    let project = read_project_config(&config, "")?;
+    let mut projects = HashMap::new();
+    projects.insert(DEFAULT_PROJECT_NAME.to_owned(), project.clone());
+    Ok(ConfigFile {
+        schema: project.schema,
+        documents: project.documents,
+        generate: project.generate,
+        projects: Some(projects),
+    })
+}
+
+/// Reads the `schema`/`documents`/`extensions.nitrogql` trio shared by both
+/// the single-project config shape and each entry under `projects`.
+/// `path` is the dotted key path of `config` itself, used to report
+/// actionable errors (e.g. `projects.admin.schema`); pass `""` for the
+/// top-level config.
+fn read_project_config(config: &Value, path: &str) -> Result<ProjectConfig, ConfigError> {
+    let schema = config
+        .get("schema")
+        .map(|schema| read_string_or_list(schema, &key_path(path, "schema")))
+        .transpose()?;
+    let documents = config
+        .get("documents")
+        .map(|documents| read_string_or_list(documents, &key_path(path, "documents")))
+        .transpose()?;
+    let generate = match config.get("extensions") {
+        None => GenerateConfig::default(),
+        Some(extensions) => {
+            let extensions = extensions.as_mapping().ok_or_else(|| ConfigError::InvalidMapping {
+                key: key_path(path, "extensions"),
+            })?;
+            match extensions.get("nitrogql") {
+                None => GenerateConfig::default(),
+                Some(nitrogql) => {
+                    let nitrogql_path = key_path(path, "extensions.nitrogql");
+                    let nitrogql = nitrogql.as_mapping().ok_or_else(|| ConfigError::InvalidMapping {
+                        key: nitrogql_path.clone(),
+                    })?;
+                    generate_config(nitrogql, &nitrogql_path)?
+                }
+            }
         }
-        None
     };
-    let extensions = config
-        .get("extensions")
-        .and_then(|e| e.get("nitrogql"))
-        .and_then(|e| e.as_mapping());
-    let generate = extensions.map(generate_config).unwrap_or_default();
-    Some(ConfigFile {
+    Ok(ProjectConfig {
         schema,
         documents,
         generate,
     })
 }
 
+/// Reads a YAML value that may be either a single string or a list of
+/// strings, as accepted by `schema` and `documents`.
+fn read_string_or_list(value: &Value, key: &str) -> Result<Vec<String>, ConfigError> {
+    if let Some(string) = value.as_str() {
+        return Ok(vec![string.to_owned()]);
+    }
+    if let Some(seq) = value.as_sequence() {
+        return seq
+            .iter()
+            .map(|value| {
+                value
+                    .as_str()
+                    .map(|s| s.to_owned())
+                    .ok_or_else(|| ConfigError::InvalidStringOrList {
+                        key: key.to_owned(),
+                    })
+            })
+            .collect();
+    }
+    Err(ConfigError::InvalidStringOrList {
+        key: key.to_owned(),
+    })
+}
+
 /// Reads extensions.generate config.
-fn generate_config(extensions: &Mapping) -> GenerateConfig {
+fn generate_config(extensions: &Mapping, path: &str) -> Result<GenerateConfig, ConfigError> {
     let mut config = GenerateConfig::default();
     let Some(generate) = extensions.get("generate") else {
-        return config;
+        return Ok(config);
     };
+    let path = key_path(path, "generate");
+    let generate = generate.as_mapping().ok_or_else(|| ConfigError::InvalidMapping {
+        key: path.clone(),
+    })?;
 
-    if let Some(schema_output) = generate
-        .get("schema-output")
-        .and_then(|path| path.as_str())
-        .map(PathBuf::from)
-    {
-        config.schema_output = Some(schema_output);
+    if let Some(schema_output) = generate.get("schema-output") {
+        let key = key_path(&path, "schema-output");
+        let schema_output = schema_output
+            .as_str()
+            .ok_or(ConfigError::InvalidString { key })?;
+        config.schema_output = Some(PathBuf::from(schema_output));
     }
-    if let Some(mode) = generate
-        .get("mode")
-        .and_then(|v| v.as_str())
-        .and_then(GenerateMode::from_str)
-    {
-        config.mode = mode;
+    if let Some(mode) = generate.get("mode") {
+        let key = key_path(&path, "mode");
+        let mode_str = mode
+            .as_str()
+            .ok_or_else(|| ConfigError::InvalidString { key: key.clone() })?;
+        config.mode = GenerateMode::from_str(mode_str).ok_or(ConfigError::UnknownGenerateMode {
+            key,
+            found: mode_str.to_owned(),
+        })?;
     }
-    if let Some(default_export_for_operation) = generate
-        .get("defaultExportForOperation")
-        .and_then(|v| v.as_bool())
-    {
-        config.default_export_for_operation = default_export_for_operation;
+    if let Some(default_export_for_operation) = generate.get("defaultExportForOperation") {
+        let key = key_path(&path, "defaultExportForOperation");
+        config.default_export_for_operation = default_export_for_operation
+            .as_bool()
+            .ok_or(ConfigError::InvalidBool { key })?;
     }
 
-    config
+    Ok(config)
+}
+
+/// Joins a dotted key path with its next segment, for use in error messages.
+fn key_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_project_config_is_back_compat() {
+        let config = parse_config(
+            "
+            schema: schema.graphql
+            documents: documents/**/*.graphql
+            ",
+        )
+        .unwrap();
+        assert_eq!(config.schema, Some(vec!["schema.graphql".to_owned()]));
+        assert_eq!(
+            config.documents,
+            Some(vec!["documents/**/*.graphql".to_owned()])
+        );
+        let projects = config.projects.unwrap();
+        let default_project = projects.get(DEFAULT_PROJECT_NAME).unwrap();
+        assert_eq!(
+            default_project.schema,
+            Some(vec!["schema.graphql".to_owned()])
+        );
+    }
+
+    #[test]
+    fn schema_and_documents_accept_a_list() {
+        let config = parse_config(
+            "
+            schema:
+              - a.graphql
+              - b.graphql
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            config.schema,
+            Some(vec!["a.graphql".to_owned(), "b.graphql".to_owned()])
+        );
+    }
+
+    #[test]
+    fn explicit_projects_map_is_parsed_per_project() {
+        let config = parse_config(
+            "
+            projects:
+              admin:
+                schema: admin/schema.graphql
+                documents: admin/documents/**/*.graphql
+              shop:
+                schema: shop/schema.graphql
+            ",
+        )
+        .unwrap();
+        assert_eq!(config.schema, None);
+        assert_eq!(config.documents, None);
+        let projects = config.projects.unwrap();
+        assert_eq!(projects.len(), 2);
+        assert_eq!(
+            projects.get("admin").unwrap().schema,
+            Some(vec!["admin/schema.graphql".to_owned()])
+        );
+        assert_eq!(
+            projects.get("shop").unwrap().schema,
+            Some(vec!["shop/schema.graphql".to_owned()])
+        );
+    }
+
+    #[test]
+    fn non_mapping_projects_is_reported() {
+        let err = parse_config("projects: oops").unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::InvalidMapping { key } if key == "projects"
+        ));
+    }
+
+    #[test]
+    fn invalid_yaml_is_reported() {
+        let err = parse_config(": not yaml").unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidYaml(_)));
+    }
+
+    #[test]
+    fn schema_with_wrong_type_is_reported() {
+        let err = parse_config("schema: 123").unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::InvalidStringOrList { key } if key == "schema"
+        ));
+    }
+
+    #[test]
+    fn non_mapping_extensions_is_reported() {
+        let err = parse_config("extensions: oops").unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::InvalidMapping { key } if key == "extensions"
+        ));
+    }
+
+    #[test]
+    fn non_mapping_nitrogql_extension_is_reported() {
+        let err = parse_config("extensions:\n  nitrogql: oops").unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::InvalidMapping { key } if key == "extensions.nitrogql"
+        ));
+    }
+
+    #[test]
+    fn non_mapping_generate_is_reported() {
+        let err = parse_config(
+            "
+            extensions:
+              nitrogql:
+                generate: oops
+            ",
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::InvalidMapping { key } if key == "extensions.nitrogql.generate"
+        ));
+    }
+
+    #[test]
+    fn unknown_generate_mode_is_reported() {
+        let err = parse_config(
+            "
+            extensions:
+              nitrogql:
+                generate:
+                  mode: not-a-real-mode
+            ",
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::UnknownGenerateMode { key, found }
+                if key == "extensions.nitrogql.generate.mode" && found == "not-a-real-mode"
+        ));
+    }
+
+    #[test]
+    fn non_boolean_default_export_for_operation_is_reported() {
+        let err = parse_config(
+            "
+            extensions:
+              nitrogql:
+                generate:
+                  defaultExportForOperation: yes-please
+            ",
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::InvalidBool { key }
+                if key == "extensions.nitrogql.generate.defaultExportForOperation"
+        ));
+    }
 }
@@ -1,5 +1,6 @@
 use std::{borrow::Cow, io, path::Path};
 
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use nitrogql_ast::base::HasPos;
 use nitrogql_utils::relative_path;
 
@@ -157,6 +158,22 @@ pub fn print_source_map_json(
     names: &[String],
     source_map: &str,
     buffer: &mut String,
+) -> io::Result<()> {
+    print_source_map_json_with_contents(file, source_files, &[], names, source_map, buffer)
+}
+
+/// Like [`print_source_map_json`], but also embeds the original source text
+/// as `sourcesContent`, aligned with `source_files`, so the map is
+/// self-contained and doesn't depend on the `.graphql` files staying on disk
+/// at their recorded relative paths. Pass an empty slice to omit
+/// `sourcesContent` entirely, as [`print_source_map_json`] does.
+pub fn print_source_map_json_with_contents(
+    file: &Path,
+    source_files: &[&Path],
+    source_contents: &[&str],
+    names: &[String],
+    source_map: &str,
+    buffer: &mut String,
 ) -> io::Result<()> {
     let sources = source_files
         .iter()
@@ -177,7 +194,40 @@ pub fn print_source_map_json(
     );
     json_writer.value("sourceRoot", "");
     json_writer.value("sources", &sources);
+    if !source_contents.is_empty() {
+        json_writer.value("sourcesContent", source_contents);
+    }
     json_writer.value("names", names);
     json_writer.value("mappings", source_map);
     Ok(())
 }
+
+/// Like [`print_source_map_json_with_contents`], but instead of writing the
+/// source map's JSON into `buffer`, it base64-encodes the JSON and appends
+/// it to `buffer` as a `//# sourceMappingURL=` comment carrying an inline
+/// `data:` URI. This matches how bundlers ship inline source maps, so
+/// generated TypeScript stays debuggable without a separate `.map` file
+/// sitting next to it.
+pub fn print_inline_source_map(
+    file: &Path,
+    source_files: &[&Path],
+    source_contents: &[&str],
+    names: &[String],
+    source_map: &str,
+    buffer: &mut String,
+) -> io::Result<()> {
+    let mut map_json = String::new();
+    print_source_map_json_with_contents(
+        file,
+        source_files,
+        source_contents,
+        names,
+        source_map,
+        &mut map_json,
+    )?;
+    let encoded = STANDARD.encode(map_json.as_bytes());
+    buffer.push_str("\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,");
+    buffer.push_str(&encoded);
+    buffer.push('\n');
+    Ok(())
+}
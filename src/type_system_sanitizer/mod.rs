@@ -5,9 +5,12 @@ use thiserror::Error;
 use crate::graphql_parser::ast::{
     base::{HasPos, Ident, Pos},
     directive::Directive,
+    r#type::Type,
     type_system::{
-        ArgumentsDefinition, DirectiveDefinition, ObjectTypeDefinition, ScalarTypeDefinition,
-        SchemaDefinition, TypeDefinition, TypeSystemDefinition,
+        ArgumentsDefinition, DirectiveDefinition, EnumTypeDefinition, FieldDefinition,
+        InputObjectTypeDefinition, InterfaceTypeDefinition, ObjectTypeDefinition,
+        ScalarTypeDefinition, SchemaDefinition, TypeDefinition, TypeSystemDefinition,
+        UnionTypeDefinition,
     },
     TypeSystemDocument,
 };
@@ -50,7 +53,18 @@ pub fn check_type_system_document(document: &TypeSystemDocument) -> Vec<CheckTyp
                 TypeDefinition::Object(ref d) => {
                     check_object(d, &definition_map, &mut result);
                 }
-                _ => {}
+                TypeDefinition::Interface(ref d) => {
+                    check_interface(d, &definition_map, &mut result);
+                }
+                TypeDefinition::Union(ref d) => {
+                    check_union(d, &definition_map, &mut result);
+                }
+                TypeDefinition::Enum(ref d) => {
+                    check_enum(d, &definition_map, &mut result);
+                }
+                TypeDefinition::InputObject(ref d) => {
+                    check_input_object(d, &definition_map, &mut result);
+                }
             },
             TypeSystemDefinition::DirectiveDefinition(ref d) => {
                 check_directive(d, &definition_map, &mut result);
@@ -84,6 +98,72 @@ pub enum CheckTypeSystemError {
     NoOutputType { position: Pos, name: String },
     #[error("Input type '{name}' is not allowed here")]
     NoInputType { position: Pos, name: String },
+    #[error("Type '{name}' is not an interface")]
+    NotInterface { position: Pos, name: String },
+    #[error("Type '{name}' does not implement interface '{interface_name}'; field '{field_name}' is missing")]
+    MissingInterfaceField {
+        position: Pos,
+        name: String,
+        interface_name: String,
+        field_name: String,
+    },
+    #[error(
+        "Field '{field_name}' of type '{name}' is not compatible with field '{field_name}' of interface '{interface_name}'"
+    )]
+    InvalidFieldTypeForInterface {
+        position: Pos,
+        name: String,
+        interface_name: String,
+        field_name: String,
+    },
+    #[error("Type '{name}' does not transitively implement interfaces required by its declared interfaces: {}", .missing.join(", "))]
+    MissingTransitiveInterfaces {
+        position: Pos,
+        name: String,
+        missing: Vec<String>,
+    },
+    #[error("Type '{name}' is missing argument '{argument_name}' of field '{field_name}' required by interface '{interface_name}'")]
+    MissingInterfaceFieldArgument {
+        position: Pos,
+        name: String,
+        interface_name: String,
+        field_name: String,
+        argument_name: String,
+    },
+    #[error("Argument '{argument_name}' of field '{field_name}' of type '{name}' is not compatible with the same argument of interface '{interface_name}'")]
+    InvalidInterfaceFieldArgumentType {
+        position: Pos,
+        name: String,
+        interface_name: String,
+        field_name: String,
+        argument_name: String,
+    },
+    #[error("Member '{name}' of union '{union_name}' is not an object type")]
+    NotObjectTypeForUnion {
+        position: Pos,
+        union_name: String,
+        name: String,
+    },
+    #[error("Enum value '{name}' is duplicated")]
+    DuplicatedEnumValue { position: Pos, name: String },
+    #[error("Argument '{name}' is not defined on directive '{directive_name}'")]
+    UnknownArgument {
+        position: Pos,
+        directive_name: String,
+        name: String,
+    },
+    #[error("Argument '{name}' is required on directive '{directive_name}' but not provided")]
+    MissingRequiredArgument {
+        position: Pos,
+        directive_name: String,
+        name: String,
+    },
+    #[error("Argument '{name}' is duplicated")]
+    DuplicatedArgument {
+        position: Pos,
+        directive_name: String,
+        name: String,
+    },
 }
 
 fn generate_definition_map<'a>(document: &'a TypeSystemDocument<'a>) -> DefinitionMap<'a> {
@@ -181,6 +261,329 @@ fn check_object(
             check_arguments_definition(arg, definitions, result)
         }
     }
+    check_implements_interfaces(
+        &object.name,
+        &object.fields,
+        &object.interfaces,
+        definitions,
+        result,
+    );
+    for f in object.fields.iter() {
+        check_directives(definitions, &f.directives, "FIELD_DEFINITION", result);
+    }
+}
+
+fn check_interface(
+    interface: &InterfaceTypeDefinition,
+    definitions: &DefinitionMap,
+    result: &mut Vec<CheckTypeSystemError>,
+) {
+    if name_starts_with_unscounsco(&interface.name) {
+        result.push(CheckTypeSystemError::UnscoUnsco {
+            position: *interface.name.position(),
+        })
+    }
+    check_directives(definitions, &interface.directives, "INTERFACE", result);
+    let mut seen_fields = vec![];
+    for f in interface.fields.iter() {
+        if seen_fields.contains(&f.name.name) {
+            result.push(CheckTypeSystemError::DuplicatedName {
+                position: *f.name.position(),
+                name: f.name.name.to_owned(),
+            });
+        } else {
+            seen_fields.push(f.name.name);
+        }
+        if name_starts_with_unscounsco(&f.name) {
+            result.push(CheckTypeSystemError::UnscoUnsco {
+                position: *f.name.position(),
+            })
+        }
+        if kind_of_type(definitions, &f.r#type).map_or(false, |k| !k.is_output_type()) {
+            result.push(CheckTypeSystemError::NoInputType {
+                position: *f.r#type.position(),
+                name: f.r#type.unwrapped_type().name.name.to_owned(),
+            });
+        }
+        if let Some(ref arg) = f.arguments {
+            check_arguments_definition(arg, definitions, result)
+        }
+    }
+    check_implements_interfaces(
+        &interface.name,
+        &interface.fields,
+        &interface.interfaces,
+        definitions,
+        result,
+    );
+}
+
+fn check_union(
+    union: &UnionTypeDefinition,
+    definitions: &DefinitionMap,
+    result: &mut Vec<CheckTypeSystemError>,
+) {
+    if name_starts_with_unscounsco(&union.name) {
+        result.push(CheckTypeSystemError::UnscoUnsco {
+            position: *union.name.position(),
+        })
+    }
+    check_directives(definitions, &union.directives, "UNION", result);
+    let mut seen_members = vec![];
+    for member in union.members.iter() {
+        if seen_members.contains(&member.name) {
+            result.push(CheckTypeSystemError::DuplicatedName {
+                position: *member.position(),
+                name: member.name.to_owned(),
+            });
+        } else {
+            seen_members.push(member.name);
+        }
+        match definitions.types.get(member.name) {
+            Some(TypeDefinition::Object(_)) => {}
+            _ => result.push(CheckTypeSystemError::NotObjectTypeForUnion {
+                position: *member.position(),
+                union_name: union.name.name.to_owned(),
+                name: member.name.to_owned(),
+            }),
+        }
+    }
+}
+
+fn check_enum(
+    enum_def: &EnumTypeDefinition,
+    definitions: &DefinitionMap,
+    result: &mut Vec<CheckTypeSystemError>,
+) {
+    if name_starts_with_unscounsco(&enum_def.name) {
+        result.push(CheckTypeSystemError::UnscoUnsco {
+            position: *enum_def.name.position(),
+        })
+    }
+    check_directives(definitions, &enum_def.directives, "ENUM", result);
+    let mut seen_values = vec![];
+    for v in enum_def.values.iter() {
+        if seen_values.contains(&v.name.name) {
+            result.push(CheckTypeSystemError::DuplicatedEnumValue {
+                position: *v.name.position(),
+                name: v.name.name.to_owned(),
+            });
+        } else {
+            seen_values.push(v.name.name);
+        }
+        if name_starts_with_unscounsco(&v.name) {
+            result.push(CheckTypeSystemError::UnscoUnsco {
+                position: *v.name.position(),
+            })
+        }
+        check_directives(definitions, &v.directives, "ENUM_VALUE", result);
+    }
+}
+
+fn check_input_object(
+    input_object: &InputObjectTypeDefinition,
+    definitions: &DefinitionMap,
+    result: &mut Vec<CheckTypeSystemError>,
+) {
+    if name_starts_with_unscounsco(&input_object.name) {
+        result.push(CheckTypeSystemError::UnscoUnsco {
+            position: *input_object.name.position(),
+        })
+    }
+    check_directives(definitions, &input_object.directives, "INPUT_OBJECT", result);
+    let mut seen_fields = vec![];
+    for f in input_object.fields.iter() {
+        if seen_fields.contains(&f.name.name) {
+            result.push(CheckTypeSystemError::DuplicatedName {
+                position: *f.name.position(),
+                name: f.name.name.to_owned(),
+            });
+        } else {
+            seen_fields.push(f.name.name);
+        }
+        if name_starts_with_unscounsco(&f.name) {
+            result.push(CheckTypeSystemError::UnscoUnsco {
+                position: *f.name.position(),
+            })
+        }
+        if kind_of_type(definitions, &f.r#type).map_or(false, |k| !k.is_input_type()) {
+            result.push(CheckTypeSystemError::NoOutputType {
+                position: *f.r#type.position(),
+                name: f.r#type.unwrapped_type().name.name.to_owned(),
+            });
+        }
+        check_directives(definitions, &f.directives, "INPUT_FIELD_DEFINITION", result);
+    }
+}
+
+/// Checks that `type_name` correctly implements all interfaces it declares,
+/// per the "implements" rules in the GraphQL spec: each declared interface
+/// must resolve to an interface type, every one of its fields must be
+/// reproduced with a covariant type and identical arguments, and every
+/// interface that it in turn implements must also be declared.
+fn check_implements_interfaces(
+    type_name: &Ident,
+    own_fields: &[FieldDefinition],
+    declared_interfaces: &[Ident],
+    definitions: &DefinitionMap,
+    result: &mut Vec<CheckTypeSystemError>,
+) {
+    let mut missing_transitive: Vec<String> = vec![];
+    for iface_name in declared_interfaces {
+        let Some(iface_def) = definitions.types.get(iface_name.name) else {
+            continue;
+        };
+        let TypeDefinition::Interface(iface_def) = iface_def else {
+            result.push(CheckTypeSystemError::NotInterface {
+                position: *iface_name.position(),
+                name: iface_name.name.to_owned(),
+            });
+            continue;
+        };
+        for iface_field in iface_def.fields.iter() {
+            let Some(own_field) = own_fields
+                .iter()
+                .find(|f| f.name.name == iface_field.name.name)
+            else {
+                result.push(CheckTypeSystemError::MissingInterfaceField {
+                    position: *type_name.position(),
+                    name: type_name.name.to_owned(),
+                    interface_name: iface_name.name.to_owned(),
+                    field_name: iface_field.name.name.to_owned(),
+                });
+                continue;
+            };
+            if !is_covariant_type(&own_field.r#type, &iface_field.r#type, definitions) {
+                result.push(CheckTypeSystemError::InvalidFieldTypeForInterface {
+                    position: *own_field.r#type.position(),
+                    name: type_name.name.to_owned(),
+                    interface_name: iface_name.name.to_owned(),
+                    field_name: iface_field.name.name.to_owned(),
+                });
+            }
+            let iface_args = iface_field
+                .arguments
+                .as_ref()
+                .map_or(&[][..], |args| &args.input_values[..]);
+            let own_args = own_field
+                .arguments
+                .as_ref()
+                .map_or(&[][..], |args| &args.input_values[..]);
+            for iface_arg in iface_args {
+                match own_args.iter().find(|a| a.name.name == iface_arg.name.name) {
+                    None => result.push(CheckTypeSystemError::MissingInterfaceFieldArgument {
+                        position: *own_field.name.position(),
+                        name: type_name.name.to_owned(),
+                        interface_name: iface_name.name.to_owned(),
+                        field_name: iface_field.name.name.to_owned(),
+                        argument_name: iface_arg.name.name.to_owned(),
+                    }),
+                    Some(own_arg) if own_arg.r#type != iface_arg.r#type => {
+                        result.push(CheckTypeSystemError::InvalidInterfaceFieldArgumentType {
+                            position: *own_arg.r#type.position(),
+                            name: type_name.name.to_owned(),
+                            interface_name: iface_name.name.to_owned(),
+                            field_name: iface_field.name.name.to_owned(),
+                            argument_name: iface_arg.name.name.to_owned(),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        for transitive in transitive_interface_closure(iface_def, definitions) {
+            if !declared_interfaces.iter().any(|i| i.name == transitive.name)
+                && !missing_transitive.iter().any(|name| name == transitive.name)
+            {
+                missing_transitive.push(transitive.name.to_owned());
+            }
+        }
+    }
+    if !missing_transitive.is_empty() {
+        result.push(CheckTypeSystemError::MissingTransitiveInterfaces {
+            position: *type_name.position(),
+            name: type_name.name.to_owned(),
+            missing: missing_transitive,
+        });
+    }
+}
+
+/// Returns every interface that `iface_def` implements, directly or
+/// transitively, so requirement (d) ("the type transitively implements
+/// every interface that `X` itself implements") can be checked against the
+/// full closure instead of only `iface_def`'s immediate superinterfaces.
+fn transitive_interface_closure<'a>(
+    iface_def: &'a InterfaceTypeDefinition,
+    definitions: &DefinitionMap<'a>,
+) -> Vec<&'a Ident<'a>> {
+    let mut seen = HashSet::new();
+    let mut result = vec![];
+    let mut queue: Vec<&Ident> = iface_def.interfaces.iter().collect();
+    while let Some(next) = queue.pop() {
+        if !seen.insert(next.name) {
+            continue;
+        }
+        result.push(next);
+        if let Some(TypeDefinition::Interface(next_def)) = definitions.types.get(next.name) {
+            queue.extend(next_def.interfaces.iter());
+        }
+    }
+    result
+}
+
+/// Returns whether `candidate` is a valid field type for implementing a
+/// field declared as `required` on an interface: either the same type,
+/// `required` with non-null added, or (for named types) a type that is a
+/// member/implementer of `required`.
+fn is_covariant_type(candidate: &Type, required: &Type, definitions: &DefinitionMap) -> bool {
+    if let Type::NonNull(candidate_inner) = candidate {
+        if let Type::NonNull(required_inner) = required {
+            return is_covariant_type(candidate_inner, required_inner, definitions);
+        }
+        return is_covariant_type(candidate_inner, required, definitions);
+    }
+    match required {
+        Type::NonNull(_) => false,
+        Type::List(required_item) => match candidate {
+            Type::List(candidate_item) => {
+                is_covariant_type(candidate_item, required_item, definitions)
+            }
+            _ => false,
+        },
+        Type::Named(required_name) => match candidate {
+            Type::Named(candidate_name) => {
+                if candidate_name.name.name == required_name.name.name {
+                    return true;
+                }
+                is_possible_type(candidate_name.name.name, required_name.name.name, definitions)
+            }
+            _ => false,
+        },
+    }
+}
+
+/// Returns whether `candidate_name` is an object or interface type that
+/// directly implements the interface `required_name`, or a member of the
+/// union `required_name`.
+fn is_possible_type(candidate_name: &str, required_name: &str, definitions: &DefinitionMap) -> bool {
+    match definitions.types.get(required_name) {
+        Some(TypeDefinition::Union(union_def)) => union_def
+            .members
+            .iter()
+            .any(|member| member.name == candidate_name),
+        Some(TypeDefinition::Interface(_)) => match definitions.types.get(candidate_name) {
+            Some(TypeDefinition::Object(object_def)) => object_def
+                .interfaces
+                .iter()
+                .any(|i| i.name == required_name),
+            Some(TypeDefinition::Interface(interface_def)) => interface_def
+                .interfaces
+                .iter()
+                .any(|i| i.name == required_name),
+            _ => false,
+        },
+        _ => false,
+    }
 }
 
 fn check_arguments_definition(
@@ -255,7 +658,64 @@ fn check_directives(
                 } else {
                     seen_directives.push(d.name.name);
                 }
+                check_directive_arguments(d, def, result);
             }
         }
     }
+}
+
+/// Validates arguments applied to a directive against its `ArgumentsDefinition`:
+/// flags unknown argument names, missing required (non-null, no default)
+/// arguments, and arguments supplied more than once.
+fn check_directive_arguments(
+    directive: &Directive,
+    def: &DirectiveDefinition,
+    result: &mut Vec<CheckTypeSystemError>,
+) {
+    let supplied_args = directive
+        .arguments
+        .as_ref()
+        .map_or(&[][..], |args| &args.arguments[..]);
+    let definition_args = def
+        .arguments
+        .as_ref()
+        .map_or(&[][..], |args| &args.input_values[..]);
+
+    let mut seen_arg_names = vec![];
+    for arg in supplied_args {
+        if seen_arg_names.contains(&arg.name.name) {
+            result.push(CheckTypeSystemError::DuplicatedArgument {
+                position: *arg.name.position(),
+                directive_name: directive.name.name.to_owned(),
+                name: arg.name.name.to_owned(),
+            });
+        } else {
+            seen_arg_names.push(arg.name.name);
+        }
+        if !definition_args
+            .iter()
+            .any(|def_arg| def_arg.name.name == arg.name.name)
+        {
+            result.push(CheckTypeSystemError::UnknownArgument {
+                position: *arg.name.position(),
+                directive_name: directive.name.name.to_owned(),
+                name: arg.name.name.to_owned(),
+            });
+        }
+    }
+    for def_arg in definition_args {
+        let is_supplied = supplied_args
+            .iter()
+            .any(|arg| arg.name.name == def_arg.name.name);
+        if !is_supplied
+            && matches!(def_arg.r#type, Type::NonNull(_))
+            && def_arg.default_value.is_none()
+        {
+            result.push(CheckTypeSystemError::MissingRequiredArgument {
+                position: *directive.position(),
+                directive_name: directive.name.name.to_owned(),
+                name: def_arg.name.name.to_owned(),
+            });
+        }
+    }
 }
\ No newline at end of file
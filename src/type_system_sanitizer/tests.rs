@@ -0,0 +1,229 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graphql_parser::parser::parse_type_system_document;
+
+fn check(source: &str) -> Vec<CheckTypeSystemError> {
+    let document = parse_type_system_document(source).expect("source should parse");
+    check_type_system_document(&document)
+}
+
+#[test]
+fn object_implementing_interface_is_valid() {
+    let errors = check(
+        "
+        interface Node { id: ID! }
+        type User implements Node { id: ID! name: String }
+        ",
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn object_missing_interface_field_is_reported() {
+    let errors = check(
+        "
+        interface Node { id: ID! }
+        type User implements Node { name: String }
+        ",
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::MissingInterfaceField { .. }]
+    ));
+}
+
+#[test]
+fn object_implementing_non_interface_is_reported() {
+    let errors = check(
+        "
+        scalar Node
+        type User implements Node { id: ID! }
+        ",
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::NotInterface { .. }]
+    ));
+}
+
+#[test]
+fn covariant_field_with_added_non_null_is_valid() {
+    let errors = check(
+        "
+        interface Node { id: ID }
+        type User implements Node { id: ID! }
+        ",
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn object_field_implementing_union_member_is_valid() {
+    let errors = check(
+        "
+        type Cat { id: ID! }
+        union Pet = Cat
+        interface Owner { pet: Pet }
+        type Person implements Owner { pet: Cat }
+        ",
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn object_missing_interface_field_argument_is_reported() {
+    let errors = check(
+        "
+        interface Node { field(limit: Int!): String }
+        type User implements Node { field: String }
+        ",
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::MissingInterfaceFieldArgument { .. }]
+    ));
+}
+
+#[test]
+fn object_mismatched_interface_field_argument_type_is_reported() {
+    let errors = check(
+        "
+        interface Node { field(limit: Int!): String }
+        type User implements Node { field(limit: String!): String }
+        ",
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::InvalidInterfaceFieldArgumentType { .. }]
+    ));
+}
+
+#[test]
+fn transitive_interface_implementation_is_checked_beyond_one_hop() {
+    let errors = check(
+        "
+        interface C { id: ID! }
+        interface B implements C { id: ID! }
+        interface A implements B & C { id: ID! }
+        type User implements A { id: ID! }
+        ",
+    );
+    let [CheckTypeSystemError::MissingTransitiveInterfaces { missing, .. }] = errors.as_slice()
+    else {
+        panic!("expected a single MissingTransitiveInterfaces error, got {errors:?}");
+    };
+    let mut missing = missing.clone();
+    missing.sort();
+    assert_eq!(missing, vec!["B".to_owned(), "C".to_owned()]);
+}
+
+#[test]
+fn union_with_non_object_member_is_reported() {
+    let errors = check(
+        "
+        scalar NotAnObject
+        union Pet = NotAnObject
+        ",
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::NotObjectTypeForUnion { .. }]
+    ));
+}
+
+#[test]
+fn enum_with_duplicated_value_is_reported() {
+    let errors = check("enum Status { ACTIVE ACTIVE }");
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::DuplicatedEnumValue { .. }]
+    ));
+}
+
+#[test]
+fn enum_with_unscounsco_value_is_reported() {
+    let errors = check("enum Status { __Active }");
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::UnscoUnsco { .. }]
+    ));
+}
+
+#[test]
+fn input_object_with_output_only_field_type_is_reported() {
+    let errors = check(
+        "
+        type NotInput { id: ID! }
+        input Filter { value: NotInput }
+        ",
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::NoOutputType { .. }]
+    ));
+}
+
+#[test]
+fn directive_with_unknown_argument_is_reported() {
+    let errors = check(
+        "
+        directive @auth(role: String!) on FIELD_DEFINITION
+        type Query { field: String @auth(role: \"admin\", extra: \"oops\") }
+        ",
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::UnknownArgument { .. }]
+    ));
+}
+
+#[test]
+fn directive_missing_required_argument_is_reported() {
+    let errors = check(
+        "
+        directive @auth(role: String!) on FIELD_DEFINITION
+        type Query { field: String @auth }
+        ",
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::MissingRequiredArgument { .. }]
+    ));
+}
+
+#[test]
+fn directive_with_optional_argument_omitted_is_valid() {
+    let errors = check(
+        "
+        directive @auth(role: String) on FIELD_DEFINITION
+        type Query { field: String @auth }
+        ",
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn directive_with_default_valued_argument_omitted_is_valid() {
+    let errors = check(
+        "
+        directive @auth(role: String! = \"user\") on FIELD_DEFINITION
+        type Query { field: String @auth }
+        ",
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn directive_with_duplicated_argument_is_reported() {
+    let errors = check(
+        "
+        directive @auth(role: String) on FIELD_DEFINITION
+        type Query { field: String @auth(role: \"admin\", role: \"user\") }
+        ",
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [CheckTypeSystemError::DuplicatedArgument { .. }]
+    ));
+}